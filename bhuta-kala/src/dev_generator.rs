@@ -1,5 +1,11 @@
 use std::io;
 
+// Vowel signs (mātrā) that can end a svarānta dhātu
+const MATRAS: &str = "ािीुूेैोौ";
+
+// Independent vowels, for dhātus that are a single vowel such as इ or ए
+const INDEPENDENT_VOWELS: &str = "अआइईउऊएऐओऔ";
+
 fn main() {
     println!("Welcome to Pāia language bhūta-kāla form generator. This tool uses devanāgarī script as default scheme. Please enter a dhātu.");
 
@@ -14,16 +20,12 @@ fn main() {
     let user_input = user_input.trim();
     
     // Remove '्' from the end of user input if available
-    let user_input = if user_input.ends_with("्") {
-        &user_input[..user_input.len() - 3]
-    } else {
-        user_input
-    };
+    let user_input = strip_virama(user_input);
 
     // Checks if the user entered a word
     if !user_input.is_empty() {
         // Determines if the input ends with a vowel
-        let ends_with_vowel = user_input.chars().last().map_or(false, |c| "ािीुू".contains(c));
+        let ends_with_vowel = is_svaranta(user_input);
 
         // Generates forms
         println!("You have entered: {}", user_input);
@@ -49,4 +51,72 @@ fn generate_result(input: &str, suffix: &str) {
 
     // Print the generated result
     println!("{}", result);
+}
+
+fn strip_virama(input: &str) -> &str {
+    // Strip on a char boundary, as '्' is a multibyte character
+    input.strip_suffix('्').unwrap_or(input)
+}
+
+fn is_svaranta(input: &str) -> bool {
+    // Only an explicit vowel marks the dhātu as svarānta; a final consonant
+    // letter is vyañjanānta whether or not '्' was typed
+    input
+        .chars()
+        .last()
+        .is_some_and(|c| MATRAS.contains(c) || INDEPENDENT_VOWELS.contains(c))
+}
+
+// Only main.rs is a cargo target, so `cargo test` does not run these.
+// Run them with `rustc --test src/dev_generator.rs` instead.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_trailing_virama_from_multi_syllable_root() {
+        assert_eq!(strip_virama("गच्छ्"), "गच्छ");
+        assert_eq!(strip_virama("गच्छ"), "गच्छ");
+    }
+
+    #[test]
+    fn lone_virama_strips_to_empty() {
+        assert_eq!(strip_virama("्"), "");
+    }
+
+    #[test]
+    fn consonant_final_root_is_vyanjananta() {
+        // Hemacandra's examples under 8.3.163, typed without '्'
+        assert!(!is_svaranta("अच्छ"));
+        assert!(!is_svaranta("हुव"));
+        assert!(!is_svaranta("गम"));
+        assert!(!is_svaranta(strip_virama("गम्")));
+    }
+
+    #[test]
+    fn matra_final_root_is_svaranta() {
+        assert!(is_svaranta("भू"));
+        assert!(is_svaranta("ने"));
+    }
+
+    #[test]
+    fn single_vowel_root_is_svaranta() {
+        assert!(is_svaranta("अ"));
+        assert!(is_svaranta("इ"));
+        assert!(is_svaranta("ए"));
+    }
+
+    #[test]
+    fn single_consonant_root_is_vyanjananta() {
+        assert!(!is_svaranta("क"));
+        assert_eq!(strip_virama("क्"), "क");
+        assert!(!is_svaranta(strip_virama("क्")));
+    }
+
+    #[test]
+    fn aspirate_and_conjunct_final_root_is_vyanjananta() {
+        for root in ["लिख", "पुच्छ", "लभ", "लिख्", "पुच्छ्", "लभ्"] {
+            assert!(!is_svaranta(strip_virama(root)), "{root}");
+        }
+    }
 }
\ No newline at end of file